@@ -28,10 +28,464 @@ use log::{Level, LevelFilter, Metadata, Record};
 
 /// This implements `log::Log`, and so can be used as a logging provider.
 /// It forwards log messages to the Windows `OutputDebugString` API.
-pub struct DebuggerLogger;
+///
+/// The set of fields included in each record (timestamp, process and thread
+/// ids, source location) is controlled by the flags on this struct. Use
+/// [`DebuggerLogger::builder`] to construct a non-default instance.
+pub struct DebuggerLogger {
+    timestamp: bool,
+    process_id: bool,
+    thread_id: bool,
+    source_location: bool,
+    filter: VLogFilter,
+    break_on: Option<Level>,
+    destinations: Destinations,
+    file: Option<std::sync::Mutex<std::io::BufWriter<std::fs::File>>>,
+    buffer: Option<std::sync::Mutex<String>>,
+    buffer_capacity: usize,
+    stack_trace: Option<Level>,
+}
+
+impl DebuggerLogger {
+    /// Constructs a logger with the default format. The default matches the
+    /// historical behavior of this crate: the source location, severity, and
+    /// message, with no timestamp or process/thread ids. No per-module filter
+    /// rules are installed, so every record up to `Debug` is enabled.
+    pub const fn new() -> Self {
+        Self {
+            timestamp: false,
+            process_id: false,
+            thread_id: false,
+            source_location: true,
+            filter: VLogFilter::new(),
+            break_on: None,
+            destinations: Destinations::DEBUGGER,
+            file: None,
+            buffer: None,
+            buffer_capacity: 0,
+            stack_trace: None,
+        }
+    }
+
+    /// Appends the accumulated debugger buffer, if any, as a single
+    /// `OutputDebugStringW` call and clears it. Collapsing N lines into one
+    /// call collapses N SEH exceptions into one. This is a no-op when buffering
+    /// is disabled or the buffer is empty.
+    fn drain_buffer(&self) {
+        if let Some(buffer) = &self.buffer {
+            let text = match buffer.lock() {
+                Ok(mut buf) => std::mem::take(&mut *buf),
+                Err(_) => return,
+            };
+            if !text.is_empty() {
+                output_debug_string(&text);
+            }
+        }
+    }
+
+    /// Returns a builder for configuring the record format.
+    pub const fn builder() -> DebuggerLoggerBuilder {
+        DebuggerLoggerBuilder {
+            logger: Self::new(),
+            file_path: None,
+        }
+    }
+
+    /// Formats a single record into the buffer. The whole record is built up
+    /// in one `String` so that it can be emitted with a single
+    /// `OutputDebugStringW` call; this keeps records from interleaving when
+    /// several threads log at once.
+    fn format_record(&self, record: &Record, out: &mut String) {
+        use std::fmt::Write;
+
+        if self.timestamp {
+            let _ = write!(out, "{} ", FormatTimestamp);
+        }
+        if self.process_id || self.thread_id {
+            out.push('[');
+            if self.process_id {
+                let _ = write!(out, "{}", current_process_id());
+            }
+            if self.process_id && self.thread_id {
+                out.push(':');
+            }
+            if self.thread_id {
+                let _ = write!(out, "{}", current_thread_id());
+            }
+            out.push_str("] ");
+        }
+        if self.source_location {
+            let _ = write!(
+                out,
+                "{}({}): ",
+                record.file().unwrap_or("<unknown>"),
+                record.line().unwrap_or(0)
+            );
+        }
+        let _ = write!(
+            out,
+            "{} - {}\r\n",
+            severity_name(record.level()),
+            record.args()
+        );
+
+        // Append a backtrace within the same buffer, so it stays part of the
+        // same `OutputDebugStringW` batch as the record it belongs to.
+        if let Some(level) = self.stack_trace {
+            if record.level() <= level {
+                for frame in capture_stack_trace() {
+                    let _ = write!(out, "    {:#018x}\r\n", frame);
+                }
+            }
+        }
+    }
+}
+
+impl Default for DebuggerLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for [`DebuggerLogger`]. Construct one with [`DebuggerLogger::builder`].
+pub struct DebuggerLoggerBuilder {
+    logger: DebuggerLogger,
+    file_path: Option<String>,
+}
+
+impl DebuggerLoggerBuilder {
+    /// Controls whether each record is prefixed with a timestamp, formatted
+    /// `HH:MM:SS.mmm`. The time is derived from `GetSystemTimeAsFileTime` and is
+    /// therefore **UTC**, not the local wall-clock time; convert accordingly
+    /// when correlating with other local-time logs.
+    pub fn with_timestamp(mut self, enabled: bool) -> Self {
+        self.logger.timestamp = enabled;
+        self
+    }
+
+    /// Controls whether each record includes the current process id
+    /// (`GetCurrentProcessId`).
+    pub fn with_process_id(mut self, enabled: bool) -> Self {
+        self.logger.process_id = enabled;
+        self
+    }
+
+    /// Controls whether each record includes the current thread id
+    /// (`GetCurrentThreadId`). This is useful for correlating lines with
+    /// threads in multithreaded apps.
+    pub fn with_thread_id(mut self, enabled: bool) -> Self {
+        self.logger.thread_id = enabled;
+        self
+    }
+
+    /// Controls whether each record includes the source file and line.
+    pub fn with_source_location(mut self, enabled: bool) -> Self {
+        self.logger.source_location = enabled;
+        self
+    }
+
+    /// Installs per-module verbosity rules parsed from `spec`.
+    ///
+    /// The spec is a comma-separated list of `pattern=level` entries, where
+    /// `pattern` is a glob (with `*` wildcards) matched against a record's
+    /// log target (which defaults to its module path) and `level` is either a
+    /// level name (`off`, `error`, `warn`, `info`, `debug`, `trace`) or a
+    /// Chromium-style numeric verbosity (`0`..`5`, where higher is more
+    /// verbose). A pattern must match the whole target. For example
+    /// `"net/*=3,ui::widget=warn"` turns targets beginning with `net/` up to
+    /// `Debug` while clamping the target `ui::widget` to `Warn`. A bare
+    /// entry without `=` (e.g. the `warn` in `"net/*=3,warn"`) sets the global
+    /// default level that applies when no pattern matches. Entries that fail to
+    /// parse are ignored.
+    pub fn with_filter(mut self, spec: &str) -> Self {
+        self.logger.filter = VLogFilter::parse(spec);
+        self
+    }
+
+    /// Breaks into the debugger after emitting any record at or above `level`
+    /// (where `Error` is the most severe). The break only fires when the
+    /// [`Destinations::DEBUGGER`] destination is selected and a debugger is
+    /// actually attached, so release and non-attached runs are unaffected.
+    /// This mirrors Chromium's `LOG(FATAL)`, which drops the developer into the
+    /// debugger at the point of failure.
+    pub fn break_on(mut self, level: Level) -> Self {
+        self.logger.break_on = Some(level);
+        self
+    }
+
+    /// Selects the set of destinations records are written to. The default is
+    /// [`Destinations::DEBUGGER`] only. Combine flags with `|`, e.g.
+    /// `Destinations::DEBUGGER | Destinations::STDERR`.
+    pub fn with_destinations(mut self, destinations: Destinations) -> Self {
+        self.logger.destinations = destinations;
+        self
+    }
+
+    /// Appends each formatted record to the file at `path` (opened once, for
+    /// append). Selecting a file path also enables the [`Destinations::FILE`]
+    /// sink. If the file cannot be opened at [`build`](Self::build) time, a
+    /// warning is sent to the debugger via [`output_debug_string`], the file
+    /// sink is disabled, and the other destinations still work. On non-Windows
+    /// platforms the file is never opened, so nothing is written to it.
+    pub fn with_file(mut self, path: impl Into<String>) -> Self {
+        self.file_path = Some(path.into());
+        self.logger.destinations = self.logger.destinations | Destinations::FILE;
+        self
+    }
+
+    /// Appends a captured stack trace to every record at or above `level`
+    /// (where `Error` is the most severe). The frame return addresses are
+    /// emitted one per line, as part of the same `OutputDebugStringW` batch as
+    /// the record, mirroring Chromium's practice of attaching backtraces to
+    /// failures. Symbolization via `dbghelp` (`SymFromAddr`) is intentionally
+    /// out of scope: only raw return addresses are emitted, to be resolved
+    /// against the module's map or a debugger.
+    pub fn with_stack_trace(mut self, level: Level) -> Self {
+        self.logger.stack_trace = Some(level);
+        self
+    }
+
+    /// Enables buffered emission to the debugger. Formatted records are
+    /// accumulated in a `Mutex<String>` and flushed as a single
+    /// `OutputDebugStringW` call once the buffer reaches `capacity` bytes, or
+    /// when [`DebuggerLogger::flush`](log::Log::flush) is called (e.g. at
+    /// shutdown). Batching amortizes the per-call SEH-exception cost that halts
+    /// every thread in the process. Be sure to call `flush` before exit so the
+    /// tail of the buffer is not lost.
+    pub fn with_buffer(mut self, capacity: usize) -> Self {
+        self.logger.buffer = Some(std::sync::Mutex::new(String::new()));
+        self.logger.buffer_capacity = capacity;
+        self
+    }
+
+    /// Finishes building the logger, opening the log file if one was requested.
+    pub fn build(mut self) -> DebuggerLogger {
+        if let Some(path) = self.file_path.take() {
+            match open_log_file(&path) {
+                Some(file) => {
+                    self.logger.file =
+                        Some(std::sync::Mutex::new(std::io::BufWriter::new(file)));
+                }
+                None => {
+                    output_debug_string(
+                        "Warning: DebuggerLogger failed to open the requested log file.\r\n",
+                    );
+                }
+            }
+        }
+        self.logger
+    }
+}
+
+/// A set of simultaneous logging destinations, modeled on Chromium's logging
+/// destinations. Values are combined with the `|` operator.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Destinations(u32);
 
-/// This is a static instance of `DebuggerLogger`. Since `DebuggerLogger`
-/// contains no state, this can be directly registered using `log::set_logger`.
+impl Destinations {
+    /// The Windows debugger / system log, via `OutputDebugStringW`.
+    pub const DEBUGGER: Destinations = Destinations(1 << 0);
+    /// A log file, appended to via a buffered writer.
+    pub const FILE: Destinations = Destinations(1 << 1);
+    /// The process's standard error stream.
+    pub const STDERR: Destinations = Destinations(1 << 2);
+
+    /// Returns `true` if every flag in `other` is set in `self`.
+    pub const fn contains(self, other: Destinations) -> bool {
+        (self.0 & other.0) == other.0
+    }
+}
+
+impl std::ops::BitOr for Destinations {
+    type Output = Destinations;
+
+    fn bitor(self, rhs: Destinations) -> Destinations {
+        Destinations(self.0 | rhs.0)
+    }
+}
+
+/// Opens (creating if necessary) the log file at `path` for append, via
+/// `CreateFileW`. Returns `None` on failure, or on non-Windows platforms.
+#[cfg(windows)]
+fn open_log_file(path: &str) -> Option<std::fs::File> {
+    use std::os::windows::io::FromRawHandle;
+
+    const FILE_APPEND_DATA: u32 = 0x0004;
+    const FILE_SHARE_READ: u32 = 0x0000_0001;
+    const FILE_SHARE_WRITE: u32 = 0x0000_0002;
+    const OPEN_ALWAYS: u32 = 4;
+    const FILE_ATTRIBUTE_NORMAL: u32 = 0x0000_0080;
+
+    let mut wide: Vec<u16> = path.encode_utf16().collect();
+    wide.push(0);
+    let handle = unsafe {
+        CreateFileW(
+            wide.as_ptr(),
+            FILE_APPEND_DATA,
+            FILE_SHARE_READ | FILE_SHARE_WRITE,
+            core::ptr::null_mut(),
+            OPEN_ALWAYS,
+            FILE_ATTRIBUTE_NORMAL,
+            core::ptr::null_mut(),
+        )
+    };
+    // INVALID_HANDLE_VALUE is (HANDLE)-1.
+    if handle as isize == -1 {
+        return None;
+    }
+    Some(unsafe { std::fs::File::from_raw_handle(handle as _) })
+}
+
+#[cfg(not(windows))]
+fn open_log_file(_path: &str) -> Option<std::fs::File> {
+    None
+}
+
+/// A compiled set of per-module verbosity rules, modeled on Chromium's
+/// `VlogInfo`. Matching is a cheap linear scan over `rules`; the most specific
+/// matching pattern wins, and `default_level` applies when nothing matches.
+struct VLogFilter {
+    default_level: LevelFilter,
+    rules: Vec<(Glob, LevelFilter)>,
+}
+
+impl VLogFilter {
+    /// An empty filter whose default preserves the crate's historical gate of
+    /// `level <= Debug`.
+    const fn new() -> Self {
+        Self {
+            default_level: LevelFilter::Debug,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Parses a spec such as `"net/*=3,ui::widget=warn"` into a filter.
+    fn parse(spec: &str) -> Self {
+        let mut filter = Self::new();
+        for entry in spec.split(',') {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+            let (pattern, level) = match entry.rsplit_once('=') {
+                Some(parts) => parts,
+                // A bare token without `=` sets the global default level, e.g.
+                // `"net/*=3,warn"`. If it isn't a valid level it is ignored.
+                None => {
+                    if let Some(level) = parse_level_filter(entry) {
+                        filter.default_level = level;
+                    }
+                    continue;
+                }
+            };
+            let pattern = pattern.trim();
+            // An empty pattern (e.g. from `"=3"`) is malformed: it would only
+            // ever match an empty target. Treat it as a dropped entry.
+            if pattern.is_empty() {
+                continue;
+            }
+            let level = match parse_level_filter(level.trim()) {
+                Some(level) => level,
+                None => continue,
+            };
+            filter.rules.push((Glob::new(pattern), level));
+        }
+        filter
+    }
+
+    /// Returns the verbosity threshold that applies to `target`, i.e. the
+    /// level of the longest (most specific) matching rule, or the default.
+    fn threshold_for(&self, target: &str) -> LevelFilter {
+        let mut best: Option<&(Glob, LevelFilter)> = None;
+        for rule in &self.rules {
+            if rule.0.matches(target)
+                && best.is_none_or(|b| rule.0.specificity() > b.0.specificity())
+            {
+                best = Some(rule);
+            }
+        }
+        best.map_or(self.default_level, |rule| rule.1)
+    }
+}
+
+/// A simple glob supporting `*` wildcards, used to match log targets.
+struct Glob {
+    pattern: String,
+}
+
+impl Glob {
+    fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_owned(),
+        }
+    }
+
+    /// The number of literal (non-wildcard) characters in the pattern. Longer
+    /// literal patterns are treated as more specific.
+    fn specificity(&self) -> usize {
+        self.pattern.chars().filter(|&c| c != '*').count()
+    }
+
+    /// Matches `text` against the pattern, treating `*` as "any sequence".
+    fn matches(&self, text: &str) -> bool {
+        glob_match(&self.pattern, text)
+    }
+}
+
+/// Classic backtracking wildcard match for a pattern containing `*`.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pat: Vec<char> = pattern.chars().collect();
+    let txt: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0usize, 0usize);
+    let (mut star, mut mark) = (None, 0usize);
+    while t < txt.len() {
+        if p < pat.len() && (pat[p] == txt[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pat.len() && pat[p] == '*' {
+            star = Some(p);
+            mark = t;
+            p += 1;
+        } else if let Some(sp) = star {
+            p = sp + 1;
+            mark += 1;
+            t = mark;
+        } else {
+            return false;
+        }
+    }
+    while p < pat.len() && pat[p] == '*' {
+        p += 1;
+    }
+    p == pat.len()
+}
+
+/// Parses a level spec: either a `LevelFilter` name or a Chromium-style numeric
+/// verbosity (`0` = `Error`, `1` = `Warn`, `2` = `Info`, `3` = `Debug`,
+/// `4`/`5` = `Trace`). Returns `None` if it cannot be parsed.
+fn parse_level_filter(s: &str) -> Option<LevelFilter> {
+    if let Ok(n) = s.parse::<u32>() {
+        return Some(match n {
+            0 => LevelFilter::Error,
+            1 => LevelFilter::Warn,
+            2 => LevelFilter::Info,
+            3 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        });
+    }
+    match s.to_ascii_lowercase().as_str() {
+        "off" => Some(LevelFilter::Off),
+        "error" => Some(LevelFilter::Error),
+        "warn" | "warning" => Some(LevelFilter::Warn),
+        "info" => Some(LevelFilter::Info),
+        "debug" => Some(LevelFilter::Debug),
+        "trace" => Some(LevelFilter::Trace),
+        _ => None,
+    }
+}
+
+/// This is a static instance of `DebuggerLogger`, configured with the default
+/// format. It can be directly registered using `log::set_logger`.
 ///
 /// Example:
 ///
@@ -46,27 +500,153 @@ pub struct DebuggerLogger;
 /// info!("Hello, world!");
 /// debug!("Hello, world, in detail!");
 /// ```
-pub static DEBUGGER_LOGGER: DebuggerLogger = DebuggerLogger;
+pub static DEBUGGER_LOGGER: DebuggerLogger = DebuggerLogger::new();
 
 impl log::Log for DebuggerLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= Level::Debug
+        metadata.level() <= self.filter.threshold_for(metadata.target())
     }
 
     fn log(&self, record: &Record) {
-        if self.enabled(record.metadata()) && is_debugger_present() {
-            let s = format!(
-                "{}({}): {} - {}\r\n",
-                record.file().unwrap_or("<unknown>"),
-                record.line().unwrap_or(0),
-                record.level(),
-                record.args()
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let mut s = String::new();
+        self.format_record(record, &mut s);
+
+        if self.destinations.contains(Destinations::DEBUGGER) && is_debugger_present() {
+            match &self.buffer {
+                Some(buffer) => {
+                    let mut over_threshold = false;
+                    if let Ok(mut buf) = buffer.lock() {
+                        buf.push_str(&s);
+                        over_threshold = buf.len() >= self.buffer_capacity;
+                    }
+                    if over_threshold {
+                        self.drain_buffer();
+                    }
+                }
+                None => output_debug_string(&s),
+            }
+
+            // Emit first, then break, so the message is visible in the
+            // debugger output window before execution stops. A debugger is
+            // known to be attached here. Drain any buffered lines so nothing
+            // is lost behind the breakpoint.
+            if let Some(break_level) = self.break_on {
+                if record.level() <= break_level {
+                    self.drain_buffer();
+                    debug_break();
+                }
+            }
+        }
+
+        if self.destinations.contains(Destinations::FILE) {
+            if let Some(file) = &self.file {
+                use std::io::Write;
+                if let Ok(mut writer) = file.lock() {
+                    let _ = writer.write_all(s.as_bytes());
+                }
+            }
+        }
+
+        if self.destinations.contains(Destinations::STDERR) {
+            eprint!("{}", s);
+        }
+    }
+
+    fn flush(&self) {
+        self.drain_buffer();
+        if let Some(file) = &self.file {
+            use std::io::Write;
+            if let Ok(mut writer) = file.lock() {
+                let _ = writer.flush();
+            }
+        }
+    }
+}
+
+/// Registers a `DebuggerLogger` instance as the currently-active logger.
+///
+/// The instance is leaked so that it satisfies the `'static` lifetime that
+/// `log::set_logger` requires. Like [`init`], this swallows registration
+/// errors rather than panicking, because the logger is intended for debugging.
+pub fn register(logger: DebuggerLogger) {
+    let logger: &'static DebuggerLogger = Box::leak(Box::new(logger));
+    match log::set_logger(logger) {
+        Ok(()) => {}
+        Err(_) => {
+            output_debug_string(
+                "Warning: Failed to register DebuggerLogger as the current Rust logger.\r\n",
             );
-            output_debug_string(&s);
         }
     }
+}
 
-    fn flush(&self) {}
+/// The fixed severity-name table, mirroring Chromium's logging: `INFO`,
+/// `WARNING`, `ERROR`, and `FATAL`. `log` has no `FATAL` level, so the most
+/// severe level (`Error`) maps to `ERROR` and `Trace`/`Debug` fall through to
+/// their own names.
+fn severity_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "ERROR",
+        Level::Warn => "WARNING",
+        Level::Info => "INFO",
+        Level::Debug => "DEBUG",
+        Level::Trace => "TRACE",
+    }
+}
+
+/// Helper that formats the current time as `HH:MM:SS.mmm` through the `Display`
+/// trait, so it can be written directly into the record buffer. The time comes
+/// from `GetSystemTimeAsFileTime` and is UTC, not local.
+struct FormatTimestamp;
+
+impl std::fmt::Display for FormatTimestamp {
+    #[cfg(windows)]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut ft = FILETIME::default();
+        let mut st = SYSTEMTIME::default();
+        unsafe {
+            GetSystemTimeAsFileTime(&mut ft);
+            FileTimeToSystemTime(&ft, &mut st);
+        }
+        write!(
+            f,
+            "{:02}:{:02}:{:02}.{:03}",
+            st.wHour, st.wMinute, st.wSecond, st.wMilliseconds
+        )
+    }
+
+    #[cfg(not(windows))]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "00:00:00.000")
+    }
+}
+
+/// Returns the current process id, or 0 on non-Windows platforms.
+fn current_process_id() -> u32 {
+    #[cfg(windows)]
+    {
+        unsafe { GetCurrentProcessId() }
+    }
+    #[cfg(not(windows))]
+    {
+        0
+    }
+}
+
+/// Returns the current thread id, or 0 on non-Windows platforms.
+fn current_thread_id() -> u32 {
+    #[cfg(windows)]
+    {
+        unsafe { GetCurrentThreadId() }
+    }
+    #[cfg(not(windows))]
+    {
+        0
+    }
 }
 
 /// Calls the `OutputDebugString` API to log a string.
@@ -87,10 +667,88 @@ pub fn output_debug_string(s: &str) {
     }
 }
 
+#[cfg(windows)]
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+#[allow(non_snake_case, dead_code)]
+struct FILETIME {
+    dwLowDateTime: u32,
+    dwHighDateTime: u32,
+}
+
+#[cfg(windows)]
+#[derive(Clone, Copy, Default)]
+#[repr(C)]
+#[allow(non_snake_case, dead_code)]
+struct SYSTEMTIME {
+    wYear: u16,
+    wMonth: u16,
+    wDayOfWeek: u16,
+    wDay: u16,
+    wHour: u16,
+    wMinute: u16,
+    wSecond: u16,
+    wMilliseconds: u16,
+}
+
 #[cfg(windows)]
 extern "stdcall" {
     fn OutputDebugStringW(chars: *const u16);
     fn IsDebuggerPresent() -> i32;
+    fn GetCurrentProcessId() -> u32;
+    fn GetCurrentThreadId() -> u32;
+    fn GetSystemTimeAsFileTime(time: *mut FILETIME);
+    fn FileTimeToSystemTime(file_time: *const FILETIME, system_time: *mut SYSTEMTIME) -> i32;
+    fn DebugBreak();
+    fn CreateFileW(
+        file_name: *const u16,
+        desired_access: u32,
+        share_mode: u32,
+        security_attributes: *mut core::ffi::c_void,
+        creation_disposition: u32,
+        flags_and_attributes: u32,
+        template_file: *mut core::ffi::c_void,
+    ) -> *mut core::ffi::c_void;
+}
+
+/// Captures the current call stack and returns the frame return addresses
+/// (instruction pointers), innermost first.
+///
+/// On non-Windows platforms, this returns an empty vector. On Windows it uses
+/// `RtlCaptureStackBackTrace`; note that a single call can capture at most a
+/// few dozen frames. The addresses are not symbolized: resolving them to
+/// symbol names via `dbghelp`/`SymFromAddr` is intentionally left to the caller
+/// (or a debugger) and is out of scope for this crate.
+pub fn capture_stack_trace() -> Vec<usize> {
+    #[cfg(windows)]
+    {
+        // RtlCaptureStackBackTrace captures at most 62 frames in a single call
+        // on older Windows versions, so that makes a safe buffer size.
+        const MAX_FRAMES: usize = 62;
+        let mut frames: [*mut core::ffi::c_void; MAX_FRAMES] = [core::ptr::null_mut(); MAX_FRAMES];
+        // Skip this frame itself.
+        let captured = unsafe {
+            RtlCaptureStackBackTrace(1, MAX_FRAMES as u32, frames.as_mut_ptr(), core::ptr::null_mut())
+        };
+        frames[..captured as usize]
+            .iter()
+            .map(|&frame| frame as usize)
+            .collect()
+    }
+    #[cfg(not(windows))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(windows)]
+extern "stdcall" {
+    fn RtlCaptureStackBackTrace(
+        frames_to_skip: u32,
+        frames_to_capture: u32,
+        back_trace: *mut *mut core::ffi::c_void,
+        back_trace_hash: *mut u32,
+    ) -> u16;
 }
 
 /// Checks whether a debugger is attached to the current process.
@@ -109,6 +767,23 @@ pub fn is_debugger_present() -> bool {
     }
 }
 
+/// Breaks into the attached debugger, via the `DebugBreak` API.
+///
+/// On non-Windows platforms, this function does nothing. Note that calling this
+/// when no debugger is attached will, on Windows, raise an unhandled breakpoint
+/// exception; callers that only want to break under a debugger should gate this
+/// on [`is_debugger_present`].
+///
+/// See [`DebugBreak`](https://docs.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-debugbreak).
+pub fn debug_break() {
+    #[cfg(windows)]
+    {
+        unsafe {
+            DebugBreak();
+        }
+    }
+}
+
 /// Sets the `DebuggerLogger` as the currently-active logger.
 ///
 /// If an error occurs when registering `DebuggerLogger` as the current logger,
@@ -128,6 +803,15 @@ pub fn init() {
     }
 }
 
+/// Registers a default-formatted `DebuggerLogger` whose per-module verbosity
+/// rules are parsed from `spec` (see [`DebuggerLoggerBuilder::with_filter`] for
+/// the syntax). Like [`init`], registration errors are swallowed rather than
+/// panicking. The caller is still responsible for setting `log::set_max_level`
+/// high enough that records reach the logger's own `enabled` check.
+pub fn init_with_filter(spec: &str) {
+    register(DebuggerLogger::builder().with_filter(spec).build());
+}
+
 macro_rules! define_init_at_level {
     ($func:ident, $level:ident) => {
         /// This can be called from C/C++ code to register the debug logger.
@@ -165,3 +849,116 @@ define_init_at_level!(rust_win_dbg_logger_init_trace, Trace);
 define_init_at_level!(rust_win_dbg_logger_init_debug, Debug);
 define_init_at_level!(rust_win_dbg_logger_init_warn, Warn);
 define_init_at_level!(rust_win_dbg_logger_init_error, Error);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_match_wildcards() {
+        // A bare `*` matches anything, including the empty string.
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "anything::here"));
+        // Leading and trailing wildcards.
+        assert!(glob_match("*widget", "ui::widget"));
+        assert!(glob_match("net*", "net::http"));
+        assert!(glob_match("net* http", "net is http"));
+        // An empty pattern matches only the empty string.
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "x"));
+        // Literal, non-matching, and anchored cases.
+        assert!(glob_match("net::http", "net::http"));
+        assert!(!glob_match("net::http", "net::https"));
+        assert!(!glob_match("net*", "ui::net"));
+    }
+
+    #[test]
+    fn parse_level_filter_numeric_and_named() {
+        assert_eq!(parse_level_filter("0"), Some(LevelFilter::Error));
+        assert_eq!(parse_level_filter("3"), Some(LevelFilter::Debug));
+        assert_eq!(parse_level_filter("5"), Some(LevelFilter::Trace));
+        assert_eq!(parse_level_filter("warn"), Some(LevelFilter::Warn));
+        assert_eq!(parse_level_filter("WARNING"), Some(LevelFilter::Warn));
+        assert_eq!(parse_level_filter("off"), Some(LevelFilter::Off));
+        assert_eq!(parse_level_filter("bogus"), None);
+        assert_eq!(parse_level_filter(""), None);
+    }
+
+    #[test]
+    fn filter_drops_malformed_entries() {
+        // `=` with no level, empty entries, and an unparseable level are all
+        // dropped, leaving no rules and the default level intact.
+        let filter = VLogFilter::parse("net=,=3,,foo=bogus");
+        assert!(filter.rules.is_empty());
+        assert_eq!(filter.default_level, LevelFilter::Debug);
+    }
+
+    #[test]
+    fn filter_bare_token_sets_default() {
+        let filter = VLogFilter::parse("net/*=3,warn");
+        assert_eq!(filter.default_level, LevelFilter::Warn);
+        assert_eq!(filter.threshold_for("ui::other"), LevelFilter::Warn);
+        assert_eq!(filter.threshold_for("net/http"), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn filter_most_specific_rule_wins() {
+        // Two rules match `net::http`; the longer (more literal) pattern wins
+        // regardless of the order they appear in the spec.
+        let filter = VLogFilter::parse("net*=1,net::http=trace");
+        assert_eq!(filter.threshold_for("net::http"), LevelFilter::Trace);
+        assert_eq!(filter.threshold_for("net::dns"), LevelFilter::Warn);
+
+        let reversed = VLogFilter::parse("net::http=trace,net*=1");
+        assert_eq!(reversed.threshold_for("net::http"), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn destinations_contains_and_bitor() {
+        let both = Destinations::DEBUGGER | Destinations::STDERR;
+        assert!(both.contains(Destinations::DEBUGGER));
+        assert!(both.contains(Destinations::STDERR));
+        assert!(!both.contains(Destinations::FILE));
+        // `contains` of a combined flag set requires every bit to be present.
+        assert!(both.contains(Destinations::DEBUGGER | Destinations::STDERR));
+        assert!(!both.contains(Destinations::DEBUGGER | Destinations::FILE));
+        // A single flag contains itself but not others.
+        assert!(Destinations::FILE.contains(Destinations::FILE));
+        assert!(!Destinations::FILE.contains(Destinations::DEBUGGER));
+    }
+
+    #[test]
+    fn buffer_threshold_and_drain() {
+        let logger = DebuggerLogger::builder().with_buffer(8).build();
+        let buffer = logger.buffer.as_ref().expect("buffering enabled");
+
+        // Under the capacity threshold: nothing should drain yet.
+        {
+            let mut buf = buffer.lock().unwrap();
+            buf.push_str("abc");
+            assert!(buf.len() < logger.buffer_capacity);
+        }
+
+        // Crossing the threshold is what triggers a drain in `log()`.
+        {
+            let mut buf = buffer.lock().unwrap();
+            buf.push_str("defghij");
+            assert!(buf.len() >= logger.buffer_capacity);
+        }
+
+        // Draining empties the accumulator (output_debug_string is a no-op off
+        // Windows, so we only assert the buffer state).
+        logger.drain_buffer();
+        assert!(buffer.lock().unwrap().is_empty());
+
+        // Draining an empty buffer is harmless.
+        logger.drain_buffer();
+        assert!(buffer.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn capture_stack_trace_empty_off_windows() {
+        assert!(capture_stack_trace().is_empty());
+    }
+}